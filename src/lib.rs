@@ -1,11 +1,25 @@
+pub mod mat3;
+pub mod mat4;
+pub mod mat_n;
+pub mod quat;
 pub mod range_extension;
+pub mod sdf;
+mod swizzle;
 pub mod utils;
 pub mod vec2;
 pub mod vec3;
+pub mod vec3a;
 pub mod vec4;
+pub mod vec_n;
 
+pub use mat3::Mat3;
+pub use mat4::Mat4;
+pub use mat_n::MatN;
+pub use quat::Quat;
 pub use range_extension::RangeExtension;
 pub use utils::Lerp;
 pub use vec2::{Point2, Vec2};
 pub use vec3::{ColorRGB, Point3, Vec3};
+pub use vec3a::Vec3A;
 pub use vec4::{ColorRGBA, Point4, Vec4};
+pub use vec_n::VecN;
@@ -0,0 +1,170 @@
+use std::ops::Mul;
+
+use crate::mat4::Mat4;
+use crate::vec2::Vec2;
+use crate::vec3::Vec3;
+
+/// Column-major 3x3 matrix, used for normal transforms and 2D affine work
+/// where a full `Mat4` would be overkill.
+#[derive(Debug, Clone, Copy)]
+pub struct Mat3(pub [f64; 9]);
+
+impl Mat3 {
+    pub const fn from_cols(cols: [f64; 9]) -> Self {
+        Self(cols)
+    }
+
+    pub const fn identity() -> Self {
+        Self([
+            1.0, 0.0, 0.0, //
+            0.0, 1.0, 0.0, //
+            0.0, 0.0, 1.0, //
+        ])
+    }
+
+    pub fn from_scale(s: Vec3) -> Self {
+        Self([
+            s.x, 0.0, 0.0, //
+            0.0, s.y, 0.0, //
+            0.0, 0.0, s.z, //
+        ])
+    }
+
+    /// 2D affine translation expressed as a 3x3 matrix acting on
+    /// homogeneous `(x, y, 1)` points.
+    pub fn from_translation_2d(t: Vec2) -> Self {
+        Self([
+            1.0,
+            0.0,
+            0.0, //
+            0.0,
+            1.0,
+            0.0, //
+            t.x(),
+            t.y(),
+            1.0, //
+        ])
+    }
+
+    pub fn from_angle_2d(radians: f64) -> Self {
+        let (sin, cos) = radians.sin_cos();
+
+        Self([
+            cos, sin, 0.0, //
+            -sin, cos, 0.0, //
+            0.0, 0.0, 1.0, //
+        ])
+    }
+
+    /// Upper-left 3x3 of a `Mat4`, suitable as a normal matrix when the
+    /// source transform has no non-uniform scale.
+    pub fn from_mat4(m: &Mat4) -> Self {
+        let c = m.0;
+
+        Self([
+            c[0], c[1], c[2], //
+            c[4], c[5], c[6], //
+            c[8], c[9], c[10], //
+        ])
+    }
+
+    fn col(&self, index: usize) -> [f64; 3] {
+        let base = index * 3;
+
+        [self.0[base], self.0[base + 1], self.0[base + 2]]
+    }
+
+    fn row(&self, index: usize) -> [f64; 3] {
+        [self.0[index], self.0[3 + index], self.0[6 + index]]
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut out = [0.0; 9];
+
+        for r in 0..3 {
+            for c in 0..3 {
+                out[c * 3 + r] = self.0[r * 3 + c];
+            }
+        }
+
+        Self(out)
+    }
+
+    pub fn determinant(&self) -> f64 {
+        let m = self.0;
+
+        m[0] * (m[4] * m[8] - m[7] * m[5]) - m[3] * (m[1] * m[8] - m[7] * m[2])
+            + m[6] * (m[1] * m[5] - m[4] * m[2])
+    }
+
+    pub fn inverse(&self) -> Option<Self> {
+        let m = self.0;
+        let det = self.determinant();
+
+        if det.abs() < 1e-12 {
+            return None;
+        }
+
+        let inv_det = 1.0 / det;
+
+        Some(Self([
+            (m[4] * m[8] - m[7] * m[5]) * inv_det,
+            (m[7] * m[2] - m[1] * m[8]) * inv_det,
+            (m[1] * m[5] - m[4] * m[2]) * inv_det,
+            (m[6] * m[5] - m[3] * m[8]) * inv_det,
+            (m[0] * m[8] - m[6] * m[2]) * inv_det,
+            (m[3] * m[2] - m[0] * m[5]) * inv_det,
+            (m[3] * m[7] - m[6] * m[4]) * inv_det,
+            (m[6] * m[1] - m[0] * m[7]) * inv_det,
+            (m[0] * m[4] - m[3] * m[1]) * inv_det,
+        ]))
+    }
+
+    /// Transforms `p` as a homogeneous 2D point (implicit `w = 1`), without
+    /// a perspective divide.
+    pub fn transform_point_2d(&self, p: Vec2) -> Vec2 {
+        let row0 = self.row(0);
+        let row1 = self.row(1);
+        let v = [p.x(), p.y(), 1.0];
+
+        let dot = |row: [f64; 3]| row[0] * v[0] + row[1] * v[1] + row[2] * v[2];
+
+        Vec2::new(dot(row0), dot(row1))
+    }
+}
+
+impl Mul<Mat3> for Mat3 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let mut out = [0.0; 9];
+
+        for c in 0..3 {
+            let rhs_col = rhs.col(c);
+
+            for r in 0..3 {
+                let lhs_row = self.row(r);
+
+                out[c * 3 + r] =
+                    lhs_row[0] * rhs_col[0] + lhs_row[1] * rhs_col[1] + lhs_row[2] * rhs_col[2];
+            }
+        }
+
+        Self(out)
+    }
+}
+
+impl Mul<Vec3> for Mat3 {
+    type Output = Vec3;
+
+    fn mul(self, rhs: Vec3) -> Self::Output {
+        let row0 = self.row(0);
+        let row1 = self.row(1);
+        let row2 = self.row(2);
+        let v = [rhs.x, rhs.y, rhs.z];
+
+        let dot = |row: [f64; 3]| row[0] * v[0] + row[1] * v[1] + row[2] * v[2];
+
+        Vec3::new(dot(row0), dot(row1), dot(row2))
+    }
+}
@@ -0,0 +1,241 @@
+use std::ops::Mul;
+
+use crate::quat::Quat;
+use crate::vec3::{Point3, Vec3};
+use crate::vec4::Vec4;
+
+/// Column-major 4x4 matrix, matching the layout GPU APIs expect.
+#[derive(Debug, Clone, Copy)]
+pub struct Mat4(pub [f64; 16]);
+
+impl Mat4 {
+    pub const fn from_cols(cols: [f64; 16]) -> Self {
+        Self(cols)
+    }
+
+    pub const fn identity() -> Self {
+        Self([
+            1.0, 0.0, 0.0, 0.0, //
+            0.0, 1.0, 0.0, 0.0, //
+            0.0, 0.0, 1.0, 0.0, //
+            0.0, 0.0, 0.0, 1.0, //
+        ])
+    }
+
+    pub fn from_translation(t: Vec3) -> Self {
+        Self([
+            1.0, 0.0, 0.0, 0.0, //
+            0.0, 1.0, 0.0, 0.0, //
+            0.0, 0.0, 1.0, 0.0, //
+            t.x, t.y, t.z, 1.0, //
+        ])
+    }
+
+    pub fn from_scale(s: Vec3) -> Self {
+        Self([
+            s.x, 0.0, 0.0, 0.0, //
+            0.0, s.y, 0.0, 0.0, //
+            0.0, 0.0, s.z, 0.0, //
+            0.0, 0.0, 0.0, 1.0, //
+        ])
+    }
+
+    pub fn from_quat(q: Quat) -> Self {
+        let Quat { x, y, z, w } = q.normalized();
+
+        let (x2, y2, z2) = (x + x, y + y, z + z);
+        let (xx, xy, xz) = (x * x2, x * y2, x * z2);
+        let (yy, yz, zz) = (y * y2, y * z2, z * z2);
+        let (wx, wy, wz) = (w * x2, w * y2, w * z2);
+
+        Self([
+            1.0 - (yy + zz),
+            xy + wz,
+            xz - wy,
+            0.0, //
+            xy - wz,
+            1.0 - (xx + zz),
+            yz + wx,
+            0.0, //
+            xz + wy,
+            yz - wx,
+            1.0 - (xx + yy),
+            0.0, //
+            0.0,
+            0.0,
+            0.0,
+            1.0, //
+        ])
+    }
+
+    pub fn look_at(eye: Point3, target: Point3, up: Vec3) -> Self {
+        let f = (target - eye).normalized();
+        let s = f.cross(up).normalized();
+        let u = s.cross(f);
+
+        Self([
+            s.x,
+            u.x,
+            -f.x,
+            0.0, //
+            s.y,
+            u.y,
+            -f.y,
+            0.0, //
+            s.z,
+            u.z,
+            -f.z,
+            0.0, //
+            -s.dot(eye),
+            -u.dot(eye),
+            f.dot(eye),
+            1.0, //
+        ])
+    }
+
+    pub fn perspective(fov_y_radians: f64, aspect: f64, near: f64, far: f64) -> Self {
+        let f = 1.0 / (fov_y_radians * 0.5).tan();
+        let range_inv = 1.0 / (near - far);
+
+        Self([
+            f / aspect,
+            0.0,
+            0.0,
+            0.0, //
+            0.0,
+            f,
+            0.0,
+            0.0, //
+            0.0,
+            0.0,
+            (near + far) * range_inv,
+            -1.0, //
+            0.0,
+            0.0,
+            near * far * range_inv * 2.0,
+            0.0, //
+        ])
+    }
+
+    fn col(&self, index: usize) -> [f64; 4] {
+        let base = index * 4;
+
+        [
+            self.0[base],
+            self.0[base + 1],
+            self.0[base + 2],
+            self.0[base + 3],
+        ]
+    }
+
+    fn row(&self, index: usize) -> [f64; 4] {
+        [
+            self.0[index],
+            self.0[4 + index],
+            self.0[8 + index],
+            self.0[12 + index],
+        ]
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut out = [0.0; 16];
+
+        for r in 0..4 {
+            for c in 0..4 {
+                out[c * 4 + r] = self.0[r * 4 + c];
+            }
+        }
+
+        Self(out)
+    }
+
+    pub fn determinant(&self) -> f64 {
+        let m = self.0;
+
+        m[0] * cofactor3x3(m, 0, 0) - m[4] * cofactor3x3(m, 0, 1) + m[8] * cofactor3x3(m, 0, 2)
+            - m[12] * cofactor3x3(m, 0, 3)
+    }
+
+    pub fn inverse(&self) -> Option<Self> {
+        let m = self.0;
+        let det = self.determinant();
+
+        if det.abs() < 1e-12 {
+            return None;
+        }
+
+        let inv_det = 1.0 / det;
+        let mut cofactors = [0.0; 16];
+
+        for col in 0..4 {
+            for row in 0..4 {
+                let sign = if (row + col) % 2 == 0 { 1.0 } else { -1.0 };
+                // Adjugate is the transpose of the cofactor matrix, so the
+                // cofactor for (row, col) is written at the transposed slot.
+                cofactors[col * 4 + row] = sign * cofactor3x3(m, col, row) * inv_det;
+            }
+        }
+
+        Some(Self(cofactors))
+    }
+
+    /// Extends `p` to a `Vec4` with `w = 1`, transforms it, and performs the
+    /// perspective divide by the resulting `w`.
+    pub fn transform_point(&self, p: Point3) -> Point3 {
+        let v = *self * p.extend(1.0);
+
+        Vec3::new(v.x / v.w, v.y / v.w, v.z / v.w)
+    }
+}
+
+/// Determinant of the 3x3 minor formed by dropping `row` and `col` from the
+/// column-major 4x4 matrix `m`.
+fn cofactor3x3(m: [f64; 16], row: usize, col: usize) -> f64 {
+    let rows: Vec<usize> = (0..4).filter(|&r| r != row).collect();
+    let cols: Vec<usize> = (0..4).filter(|&c| c != col).collect();
+
+    let at = |r: usize, c: usize| m[cols[c] * 4 + rows[r]];
+
+    at(0, 0) * (at(1, 1) * at(2, 2) - at(1, 2) * at(2, 1))
+        - at(0, 1) * (at(1, 0) * at(2, 2) - at(1, 2) * at(2, 0))
+        + at(0, 2) * (at(1, 0) * at(2, 1) - at(1, 1) * at(2, 0))
+}
+
+impl Mul<Mat4> for Mat4 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let mut out = [0.0; 16];
+
+        for c in 0..4 {
+            let rhs_col = rhs.col(c);
+
+            for r in 0..4 {
+                let lhs_row = self.row(r);
+
+                out[c * 4 + r] = lhs_row[0] * rhs_col[0]
+                    + lhs_row[1] * rhs_col[1]
+                    + lhs_row[2] * rhs_col[2]
+                    + lhs_row[3] * rhs_col[3];
+            }
+        }
+
+        Self(out)
+    }
+}
+
+impl Mul<Vec4> for Mat4 {
+    type Output = Vec4;
+
+    fn mul(self, rhs: Vec4) -> Self::Output {
+        let row0 = self.row(0);
+        let row1 = self.row(1);
+        let row2 = self.row(2);
+        let row3 = self.row(3);
+        let v = [rhs.x, rhs.y, rhs.z, rhs.w];
+
+        let dot = |row: [f64; 4]| row[0] * v[0] + row[1] * v[1] + row[2] * v[2] + row[3] * v[3];
+
+        Vec4::new(dot(row0), dot(row1), dot(row2), dot(row3))
+    }
+}
@@ -0,0 +1,127 @@
+use std::ops::Mul;
+
+use crate::vec_n::VecN;
+
+/// Generic R x C matrix, row-major, for linear algebra beyond the
+/// fixed-size `Mat3`/`Mat4` (small linear solves, arbitrary-dimension
+/// transforms).
+#[derive(Debug, Clone, Copy)]
+pub struct MatN<const R: usize, const C: usize>(pub [[f64; C]; R]);
+
+impl<const R: usize, const C: usize> MatN<R, C> {
+    pub const fn new(rows: [[f64; C]; R]) -> Self {
+        Self(rows)
+    }
+
+    pub const fn zero() -> Self {
+        Self([[0.0; C]; R])
+    }
+
+    pub fn transpose(&self) -> MatN<C, R> {
+        let mut out = [[0.0; R]; C];
+
+        for (r, row) in self.0.iter().enumerate() {
+            for (c, &val) in row.iter().enumerate() {
+                out[c][r] = val;
+            }
+        }
+
+        MatN(out)
+    }
+}
+
+impl<const R: usize, const K: usize, const C: usize> Mul<MatN<K, C>> for MatN<R, K> {
+    type Output = MatN<R, C>;
+
+    fn mul(self, rhs: MatN<K, C>) -> Self::Output {
+        let mut out = [[0.0; C]; R];
+
+        for (r, out_row) in out.iter_mut().enumerate() {
+            for (c, out_cell) in out_row.iter_mut().enumerate() {
+                *out_cell = (0..K).map(|k| self.0[r][k] * rhs.0[k][c]).sum();
+            }
+        }
+
+        MatN(out)
+    }
+}
+
+impl<const N: usize> MatN<N, N> {
+    pub fn identity() -> Self {
+        let mut out = [[0.0; N]; N];
+
+        for (i, row) in out.iter_mut().enumerate() {
+            row[i] = 1.0;
+        }
+
+        Self(out)
+    }
+
+    /// Solves `self * x = b` by Gaussian elimination with partial
+    /// pivoting: the largest-magnitude entry in each column is swapped
+    /// into the pivot row before eliminating below it, then `x` is
+    /// recovered by back-substitution. Returns `None` if `self` is
+    /// singular.
+    pub fn solve(&self, b: VecN<N>) -> Option<VecN<N>> {
+        let mut a = self.0;
+        let mut rhs = b.0;
+
+        for col in 0..N {
+            let pivot_row =
+                (col..N).max_by(|&r1, &r2| a[r1][col].abs().total_cmp(&a[r2][col].abs()))?;
+
+            if a[pivot_row][col].abs() < 1e-12 {
+                return None;
+            }
+
+            a.swap(col, pivot_row);
+            rhs.swap(col, pivot_row);
+
+            for row in (col + 1)..N {
+                let factor = a[row][col] / a[col][col];
+                let (pivot_rows, lower_rows) = a.split_at_mut(row);
+
+                for (cur, piv) in lower_rows[0]
+                    .iter_mut()
+                    .zip(pivot_rows[col].iter())
+                    .skip(col)
+                {
+                    *cur -= factor * piv;
+                }
+
+                rhs[row] -= factor * rhs[col];
+            }
+        }
+
+        let mut x = [0.0; N];
+
+        for row in (0..N).rev() {
+            let known: f64 = a[row][(row + 1)..]
+                .iter()
+                .zip(x[(row + 1)..].iter())
+                .map(|(a, x)| a * x)
+                .sum();
+
+            x[row] = (rhs[row] - known) / a[row][row];
+        }
+
+        Some(VecN::new(x))
+    }
+
+    pub fn inverse(&self) -> Option<Self> {
+        let mut columns = [[0.0; N]; N];
+
+        for i in 0..N {
+            let mut basis = [0.0; N];
+            basis[i] = 1.0;
+
+            let solved = self.solve(VecN::new(basis))?;
+
+            for (row, col_row) in columns.iter_mut().enumerate() {
+                col_row[i] = solved.0[row];
+            }
+        }
+
+        Some(Self(columns))
+    }
+}
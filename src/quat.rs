@@ -0,0 +1,155 @@
+use std::ops::{Add, Mul, Neg};
+
+use crate::vec3::Vec3;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Quat {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub w: f64,
+}
+
+impl Quat {
+    #[allow(unused)]
+    pub const IDENTITY: Self = Self::new(0.0, 0.0, 0.0, 1.0);
+
+    pub const fn new(x: f64, y: f64, z: f64, w: f64) -> Self {
+        Self { x, y, z, w }
+    }
+
+    pub fn from_axis_angle(axis: Vec3, radians: f64) -> Self {
+        let axis = axis.normalized();
+        let half = radians * 0.5;
+        let (sin_half, cos_half) = half.sin_cos();
+
+        Self {
+            x: axis.x * sin_half,
+            y: axis.y * sin_half,
+            z: axis.z * sin_half,
+            w: cos_half,
+        }
+    }
+
+    pub fn length(self) -> f64 {
+        self.length_squared().sqrt()
+    }
+
+    pub fn length_squared(self) -> f64 {
+        self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w
+    }
+
+    pub fn normalized(self) -> Self {
+        let inv_len = 1.0 / self.length();
+
+        Self {
+            x: self.x * inv_len,
+            y: self.y * inv_len,
+            z: self.z * inv_len,
+            w: self.w * inv_len,
+        }
+    }
+
+    pub fn conjugate(self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+            w: self.w,
+        }
+    }
+
+    pub fn dot(self, rhs: Self) -> f64 {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z + self.w * rhs.w
+    }
+
+    pub fn vector_part(self) -> Vec3 {
+        Vec3::new(self.x, self.y, self.z)
+    }
+
+    pub fn rotate(self, v: Vec3) -> Vec3 {
+        let qv = self.vector_part();
+        let uv = qv.cross(v);
+        let uuv = qv.cross(uv);
+
+        v + 2.0 * (self.w * uv + uuv)
+    }
+
+    /// Spherical interpolation, taking the shorter of the two arcs between
+    /// `self` and `other`. Falls back to `nlerp` when the angle between them
+    /// is too small for the sin-based weights to stay numerically stable.
+    pub fn slerp(self, other: Self, t: f64) -> Self {
+        let mut dot = self.dot(other);
+        let mut other = other;
+
+        if dot < 0.0 {
+            other = -other;
+            dot = -dot;
+        }
+
+        if dot > 0.9995 {
+            return self.nlerp(other, t);
+        }
+
+        let theta_0 = dot.clamp(-1.0, 1.0).acos();
+        let theta = theta_0 * t;
+        let (sin_theta, _) = theta.sin_cos();
+        let sin_theta_0 = theta_0.sin();
+
+        let s0 = (theta_0 - theta).sin() / sin_theta_0;
+        let s1 = sin_theta / sin_theta_0;
+
+        self * s0 + other * s1
+    }
+
+    pub fn nlerp(self, other: Self, t: f64) -> Self {
+        (self * (1.0 - t) + other * t).normalized()
+    }
+}
+
+impl Add for Quat {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+            w: self.w + rhs.w,
+        }
+    }
+}
+
+impl Neg for Quat {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y, -self.z, -self.w)
+    }
+}
+
+impl Mul<f64> for Quat {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self {
+            x: self.x * rhs,
+            y: self.y * rhs,
+            z: self.z * rhs,
+            w: self.w * rhs,
+        }
+    }
+}
+
+impl Mul<Quat> for Quat {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            y: self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            z: self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+            w: self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+        }
+    }
+}
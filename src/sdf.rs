@@ -0,0 +1,211 @@
+use crate::quat::Quat;
+use crate::utils::Lerp;
+use crate::vec2::Vec2;
+use crate::vec3::{Point3, Vec3};
+
+/// A signed distance field: `distance(p)` is negative inside the surface,
+/// positive outside, and zero on it. Implementors should keep the
+/// Lipschitz-1 property (the field changes by at most `|dt|` per unit of
+/// travel) so `march` can safely step by the returned distance.
+pub trait Sdf {
+    fn distance(&self, p: Point3) -> f64;
+}
+
+pub struct Sphere {
+    pub center: Point3,
+    pub radius: f64,
+}
+
+impl Sdf for Sphere {
+    fn distance(&self, p: Point3) -> f64 {
+        (p - self.center).length() - self.radius
+    }
+}
+
+/// Infinite plane through the origin's offset along `normal` (which must be
+/// normalized), i.e. `{ p : dot(p, normal) == offset }`.
+pub struct Plane {
+    pub normal: Vec3,
+    pub offset: f64,
+}
+
+impl Sdf for Plane {
+    fn distance(&self, p: Point3) -> f64 {
+        p.dot(self.normal) - self.offset
+    }
+}
+
+/// Axis-aligned cuboid centered on the origin, `half_extents` wide in each
+/// direction.
+pub struct Cuboid {
+    pub half_extents: Vec3,
+}
+
+impl Sdf for Cuboid {
+    fn distance(&self, p: Point3) -> f64 {
+        let q = p.abs() - self.half_extents;
+
+        q.component_max(Vec3::ZERO).length() + q.max_element().min(0.0)
+    }
+}
+
+/// Torus centered on the origin, lying in the xz-plane, revolved around the
+/// y-axis.
+pub struct Torus {
+    pub major_radius: f64,
+    pub minor_radius: f64,
+}
+
+impl Sdf for Torus {
+    fn distance(&self, p: Point3) -> f64 {
+        let q = Vec2::new(p.xz().length() - self.major_radius, p.y);
+
+        q.length() - self.minor_radius
+    }
+}
+
+/// Cylinder centered on the origin, aligned to the y-axis.
+pub struct Cylinder {
+    pub radius: f64,
+    pub half_height: f64,
+}
+
+impl Sdf for Cylinder {
+    fn distance(&self, p: Point3) -> f64 {
+        let d = Vec2::new(p.xz().length() - self.radius, p.y.abs() - self.half_height);
+
+        d.component_max(Vec2::ZERO).length() + d.max_element().min(0.0)
+    }
+}
+
+pub struct Union<A, B> {
+    pub a: A,
+    pub b: B,
+}
+
+impl<A: Sdf, B: Sdf> Sdf for Union<A, B> {
+    fn distance(&self, p: Point3) -> f64 {
+        self.a.distance(p).min(self.b.distance(p))
+    }
+}
+
+pub struct Intersection<A, B> {
+    pub a: A,
+    pub b: B,
+}
+
+impl<A: Sdf, B: Sdf> Sdf for Intersection<A, B> {
+    fn distance(&self, p: Point3) -> f64 {
+        self.a.distance(p).max(self.b.distance(p))
+    }
+}
+
+/// The region covered by `a` with `b` carved out of it.
+pub struct Subtraction<A, B> {
+    pub a: A,
+    pub b: B,
+}
+
+impl<A: Sdf, B: Sdf> Sdf for Subtraction<A, B> {
+    fn distance(&self, p: Point3) -> f64 {
+        self.a.distance(p).max(-self.b.distance(p))
+    }
+}
+
+/// Union of `a` and `b` with the seam rounded off over a region of size
+/// `k`, using Inigo Quilez's polynomial smooth-min.
+pub struct SmoothUnion<A, B> {
+    pub a: A,
+    pub b: B,
+    pub k: f64,
+}
+
+impl<A: Sdf, B: Sdf> Sdf for SmoothUnion<A, B> {
+    fn distance(&self, p: Point3) -> f64 {
+        let da = self.a.distance(p);
+        let db = self.b.distance(p);
+
+        let h = (0.5 + 0.5 * (db - da) / self.k).clamp(0.0, 1.0);
+
+        da.lerp(db, h) - self.k * h * (1.0 - h)
+    }
+}
+
+/// Rigid transform wrapper: queries `sdf` in its own local space by undoing
+/// `translation` and `rotation` on the incoming point.
+pub struct Transform<T> {
+    pub sdf: T,
+    pub translation: Vec3,
+    pub rotation: Quat,
+}
+
+impl<T: Sdf> Sdf for Transform<T> {
+    fn distance(&self, p: Point3) -> f64 {
+        let local = self.rotation.conjugate().rotate(p - self.translation);
+
+        self.sdf.distance(local)
+    }
+}
+
+/// Uniform scale wrapper. The inner field is queried in unscaled space and
+/// the result is rescaled so the output stays a true distance.
+pub struct Scale<T> {
+    pub sdf: T,
+    pub scale: f64,
+}
+
+impl<T: Sdf> Sdf for Scale<T> {
+    fn distance(&self, p: Point3) -> f64 {
+        self.sdf.distance(p / self.scale) * self.scale
+    }
+}
+
+pub struct Ray {
+    pub origin: Point3,
+    pub dir: Vec3,
+}
+
+impl Ray {
+    pub fn at(&self, t: f64) -> Point3 {
+        self.origin + self.dir * t
+    }
+}
+
+/// Sphere-traces `ray` through `scene`, advancing `t` by the current
+/// distance each step. Returns the hit distance once the field drops below
+/// `epsilon`, or `None` if `max_steps` is exhausted or `t` exceeds `far`
+/// without a hit.
+pub fn march<S: Sdf>(scene: &S, ray: &Ray, max_steps: u32, epsilon: f64, far: f64) -> Option<f64> {
+    let mut t = 0.0;
+
+    for _ in 0..max_steps {
+        let distance = scene.distance(ray.at(t));
+
+        if distance < epsilon {
+            return Some(t);
+        }
+
+        t += distance;
+
+        if t > far {
+            return None;
+        }
+    }
+
+    None
+}
+
+/// Estimates the surface normal at `p` via central differences on
+/// `scene.distance`.
+pub fn normal_at<S: Sdf>(scene: &S, p: Point3) -> Vec3 {
+    let eps = 1e-4;
+
+    let dx =
+        scene.distance(p + Vec3::new(eps, 0.0, 0.0)) - scene.distance(p - Vec3::new(eps, 0.0, 0.0));
+    let dy =
+        scene.distance(p + Vec3::new(0.0, eps, 0.0)) - scene.distance(p - Vec3::new(0.0, eps, 0.0));
+    let dz =
+        scene.distance(p + Vec3::new(0.0, 0.0, eps)) - scene.distance(p - Vec3::new(0.0, 0.0, eps));
+
+    Vec3::new(dx, dy, dz).normalized()
+}
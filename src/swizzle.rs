@@ -0,0 +1,13 @@
+/// Defines a GLSL-style swizzle accessor, e.g. `swizzle!(xy, Vec2, x, y)`
+/// expands to a `fn xy(&self) -> Vec2` that reads off `x` and `y` in order.
+/// Used to generate the full two/three/four-component permutation surface
+/// for Vec2/Vec3/Vec4 without hand-writing each method body.
+macro_rules! swizzle {
+    ($name:ident, $out:ty, $($comp:ident),+) => {
+        pub fn $name(&self) -> $out {
+            <$out>::new($(self.$comp),+)
+        }
+    };
+}
+
+pub(crate) use swizzle;
@@ -1,10 +1,13 @@
 use std::ops::{
-    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, RangeInclusive, Sub, SubAssign,
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, RangeInclusive, Sub,
+    SubAssign,
 };
 
 use rand::{thread_rng, Rng};
 
-use crate::Vec3;
+use crate::swizzle::swizzle;
+use crate::vec_n::VecN;
+use crate::{Vec3, Vec4};
 
 #[derive(Debug, Clone, Copy)]
 pub struct Vec2 {
@@ -32,6 +35,14 @@ impl Vec2 {
         Self { x: val, y: val }
     }
 
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
     pub fn length(&self) -> f64 {
         self.length_squared().sqrt()
     }
@@ -58,6 +69,46 @@ impl Vec2 {
         (self.x.abs() < s) && (self.y.abs() < s)
     }
 
+    pub fn min_element(&self) -> f64 {
+        self.x.min(self.y)
+    }
+
+    pub fn max_element(&self) -> f64 {
+        self.x.max(self.y)
+    }
+
+    pub fn abs(&self) -> Self {
+        Self::new(self.x.abs(), self.y.abs())
+    }
+
+    pub fn floor(&self) -> Self {
+        Self::new(self.x.floor(), self.y.floor())
+    }
+
+    pub fn ceil(&self) -> Self {
+        Self::new(self.x.ceil(), self.y.ceil())
+    }
+
+    pub fn clamp(&self, min: Self, max: Self) -> Self {
+        Self::new(self.x.clamp(min.x, max.x), self.y.clamp(min.y, max.y))
+    }
+
+    pub fn component_min(&self, rhs: Self) -> Self {
+        Self::new(self.x.min(rhs.x), self.y.min(rhs.y))
+    }
+
+    pub fn component_max(&self, rhs: Self) -> Self {
+        Self::new(self.x.max(rhs.x), self.y.max(rhs.y))
+    }
+
+    pub fn iter(&self) -> std::array::IntoIter<f64, 2> {
+        [self.x, self.y].into_iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::array::IntoIter<&mut f64, 2> {
+        [&mut self.x, &mut self.y].into_iter()
+    }
+
     pub fn random() -> Self {
         let mut rng = thread_rng();
         Self::new(rng.gen_range(0.0..=1.0), rng.gen_range(0.0..=1.0))
@@ -73,6 +124,41 @@ impl Vec2 {
     }
 }
 
+/// GLSL-style swizzle accessors (`v.xy()`, `v.yx()`, ...), generated by
+/// the `swizzle!` macro for every two/three/four-component permutation.
+impl Vec2 {
+    swizzle!(xx, Vec2, x, x);
+    swizzle!(xy, Vec2, x, y);
+    swizzle!(yx, Vec2, y, x);
+    swizzle!(yy, Vec2, y, y);
+
+    swizzle!(xxx, Vec3, x, x, x);
+    swizzle!(xxy, Vec3, x, x, y);
+    swizzle!(xyx, Vec3, x, y, x);
+    swizzle!(xyy, Vec3, x, y, y);
+    swizzle!(yxx, Vec3, y, x, x);
+    swizzle!(yxy, Vec3, y, x, y);
+    swizzle!(yyx, Vec3, y, y, x);
+    swizzle!(yyy, Vec3, y, y, y);
+
+    swizzle!(xxxx, Vec4, x, x, x, x);
+    swizzle!(xxxy, Vec4, x, x, x, y);
+    swizzle!(xxyx, Vec4, x, x, y, x);
+    swizzle!(xxyy, Vec4, x, x, y, y);
+    swizzle!(xyxx, Vec4, x, y, x, x);
+    swizzle!(xyxy, Vec4, x, y, x, y);
+    swizzle!(xyyx, Vec4, x, y, y, x);
+    swizzle!(xyyy, Vec4, x, y, y, y);
+    swizzle!(yxxx, Vec4, y, x, x, x);
+    swizzle!(yxxy, Vec4, y, x, x, y);
+    swizzle!(yxyx, Vec4, y, x, y, x);
+    swizzle!(yxyy, Vec4, y, x, y, y);
+    swizzle!(yyxx, Vec4, y, y, x, x);
+    swizzle!(yyxy, Vec4, y, y, x, y);
+    swizzle!(yyyx, Vec4, y, y, y, x);
+    swizzle!(yyyy, Vec4, y, y, y, y);
+}
+
 impl Add for Vec2 {
     type Output = Self;
 
@@ -191,5 +277,63 @@ impl Mul<Vec2> for f64 {
     }
 }
 
+impl Index<usize> for Vec2 {
+    type Output = f64;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            _ => panic!("index out of bounds: Vec2 has 2 components, got index {index}"),
+        }
+    }
+}
+
+impl IndexMut<usize> for Vec2 {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            _ => panic!("index out of bounds: Vec2 has 2 components, got index {index}"),
+        }
+    }
+}
+
+impl From<[f64; 2]> for Vec2 {
+    fn from(value: [f64; 2]) -> Self {
+        Self::new(value[0], value[1])
+    }
+}
+
+impl From<Vec2> for [f64; 2] {
+    fn from(value: Vec2) -> Self {
+        [value.x, value.y]
+    }
+}
+
+impl From<(f64, f64)> for Vec2 {
+    fn from(value: (f64, f64)) -> Self {
+        Self::new(value.0, value.1)
+    }
+}
+
+impl From<Vec2> for (f64, f64) {
+    fn from(value: Vec2) -> Self {
+        (value.x, value.y)
+    }
+}
+
+impl From<Vec2> for VecN<2> {
+    fn from(value: Vec2) -> Self {
+        VecN::new([value.x, value.y])
+    }
+}
+
+impl From<VecN<2>> for Vec2 {
+    fn from(value: VecN<2>) -> Self {
+        Self::new(value.0[0], value.0[1])
+    }
+}
+
 #[allow(unused)]
 pub type Point2 = Vec2;
@@ -1,10 +1,11 @@
 use std::ops::{
-    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, RangeInclusive, Sub, SubAssign,
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, RangeInclusive, Sub,
+    SubAssign,
 };
 
 use rand::{thread_rng, Rng};
 
-use crate::{utils::linear_to_gamma, vec2::Vec2, vec4::Vec4};
+use crate::{swizzle::swizzle, utils::linear_to_gamma, vec2::Vec2, vec4::Vec4, vec_n::VecN};
 
 #[derive(Debug, Default, Clone, Copy)]
 pub struct Vec3 {
@@ -111,6 +112,50 @@ impl Vec3 {
         (self.x.abs() < s) && (self.y.abs() < s) && (self.z.abs() < s)
     }
 
+    pub fn min_element(self) -> f64 {
+        self.x.min(self.y).min(self.z)
+    }
+
+    pub fn max_element(self) -> f64 {
+        self.x.max(self.y).max(self.z)
+    }
+
+    pub fn abs(self) -> Self {
+        Self::new(self.x.abs(), self.y.abs(), self.z.abs())
+    }
+
+    pub fn floor(self) -> Self {
+        Self::new(self.x.floor(), self.y.floor(), self.z.floor())
+    }
+
+    pub fn ceil(self) -> Self {
+        Self::new(self.x.ceil(), self.y.ceil(), self.z.ceil())
+    }
+
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        Self::new(
+            self.x.clamp(min.x, max.x),
+            self.y.clamp(min.y, max.y),
+            self.z.clamp(min.z, max.z),
+        )
+    }
+
+    pub fn component_min(self, rhs: Self) -> Self {
+        Self::new(self.x.min(rhs.x), self.y.min(rhs.y), self.z.min(rhs.z))
+    }
+
+    pub fn component_max(self, rhs: Self) -> Self {
+        Self::new(self.x.max(rhs.x), self.y.max(rhs.y), self.z.max(rhs.z))
+    }
+
+    pub fn iter(self) -> std::array::IntoIter<f64, 3> {
+        [self.x, self.y, self.z].into_iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::array::IntoIter<&mut f64, 3> {
+        [&mut self.x, &mut self.y, &mut self.z].into_iter()
+    }
+
     pub fn random() -> Self {
         let mut rng = thread_rng();
         Self::new(
@@ -170,6 +215,130 @@ impl Vec3 {
     }
 }
 
+/// GLSL-style swizzle accessors (`v.xy()`, `v.zyx()`, ...), generated by
+/// the `swizzle!` macro for every two/three/four-component permutation.
+impl Vec3 {
+    swizzle!(xx, Vec2, x, x);
+    swizzle!(xy, Vec2, x, y);
+    swizzle!(xz, Vec2, x, z);
+    swizzle!(yx, Vec2, y, x);
+    swizzle!(yy, Vec2, y, y);
+    swizzle!(yz, Vec2, y, z);
+    swizzle!(zx, Vec2, z, x);
+    swizzle!(zy, Vec2, z, y);
+    swizzle!(zz, Vec2, z, z);
+
+    swizzle!(xxx, Vec3, x, x, x);
+    swizzle!(xxy, Vec3, x, x, y);
+    swizzle!(xxz, Vec3, x, x, z);
+    swizzle!(xyx, Vec3, x, y, x);
+    swizzle!(xyy, Vec3, x, y, y);
+    swizzle!(xyz, Vec3, x, y, z);
+    swizzle!(xzx, Vec3, x, z, x);
+    swizzle!(xzy, Vec3, x, z, y);
+    swizzle!(xzz, Vec3, x, z, z);
+    swizzle!(yxx, Vec3, y, x, x);
+    swizzle!(yxy, Vec3, y, x, y);
+    swizzle!(yxz, Vec3, y, x, z);
+    swizzle!(yyx, Vec3, y, y, x);
+    swizzle!(yyy, Vec3, y, y, y);
+    swizzle!(yyz, Vec3, y, y, z);
+    swizzle!(yzx, Vec3, y, z, x);
+    swizzle!(yzy, Vec3, y, z, y);
+    swizzle!(yzz, Vec3, y, z, z);
+    swizzle!(zxx, Vec3, z, x, x);
+    swizzle!(zxy, Vec3, z, x, y);
+    swizzle!(zxz, Vec3, z, x, z);
+    swizzle!(zyx, Vec3, z, y, x);
+    swizzle!(zyy, Vec3, z, y, y);
+    swizzle!(zyz, Vec3, z, y, z);
+    swizzle!(zzx, Vec3, z, z, x);
+    swizzle!(zzy, Vec3, z, z, y);
+    swizzle!(zzz, Vec3, z, z, z);
+
+    swizzle!(xxxx, Vec4, x, x, x, x);
+    swizzle!(xxxy, Vec4, x, x, x, y);
+    swizzle!(xxxz, Vec4, x, x, x, z);
+    swizzle!(xxyx, Vec4, x, x, y, x);
+    swizzle!(xxyy, Vec4, x, x, y, y);
+    swizzle!(xxyz, Vec4, x, x, y, z);
+    swizzle!(xxzx, Vec4, x, x, z, x);
+    swizzle!(xxzy, Vec4, x, x, z, y);
+    swizzle!(xxzz, Vec4, x, x, z, z);
+    swizzle!(xyxx, Vec4, x, y, x, x);
+    swizzle!(xyxy, Vec4, x, y, x, y);
+    swizzle!(xyxz, Vec4, x, y, x, z);
+    swizzle!(xyyx, Vec4, x, y, y, x);
+    swizzle!(xyyy, Vec4, x, y, y, y);
+    swizzle!(xyyz, Vec4, x, y, y, z);
+    swizzle!(xyzx, Vec4, x, y, z, x);
+    swizzle!(xyzy, Vec4, x, y, z, y);
+    swizzle!(xyzz, Vec4, x, y, z, z);
+    swizzle!(xzxx, Vec4, x, z, x, x);
+    swizzle!(xzxy, Vec4, x, z, x, y);
+    swizzle!(xzxz, Vec4, x, z, x, z);
+    swizzle!(xzyx, Vec4, x, z, y, x);
+    swizzle!(xzyy, Vec4, x, z, y, y);
+    swizzle!(xzyz, Vec4, x, z, y, z);
+    swizzle!(xzzx, Vec4, x, z, z, x);
+    swizzle!(xzzy, Vec4, x, z, z, y);
+    swizzle!(xzzz, Vec4, x, z, z, z);
+    swizzle!(yxxx, Vec4, y, x, x, x);
+    swizzle!(yxxy, Vec4, y, x, x, y);
+    swizzle!(yxxz, Vec4, y, x, x, z);
+    swizzle!(yxyx, Vec4, y, x, y, x);
+    swizzle!(yxyy, Vec4, y, x, y, y);
+    swizzle!(yxyz, Vec4, y, x, y, z);
+    swizzle!(yxzx, Vec4, y, x, z, x);
+    swizzle!(yxzy, Vec4, y, x, z, y);
+    swizzle!(yxzz, Vec4, y, x, z, z);
+    swizzle!(yyxx, Vec4, y, y, x, x);
+    swizzle!(yyxy, Vec4, y, y, x, y);
+    swizzle!(yyxz, Vec4, y, y, x, z);
+    swizzle!(yyyx, Vec4, y, y, y, x);
+    swizzle!(yyyy, Vec4, y, y, y, y);
+    swizzle!(yyyz, Vec4, y, y, y, z);
+    swizzle!(yyzx, Vec4, y, y, z, x);
+    swizzle!(yyzy, Vec4, y, y, z, y);
+    swizzle!(yyzz, Vec4, y, y, z, z);
+    swizzle!(yzxx, Vec4, y, z, x, x);
+    swizzle!(yzxy, Vec4, y, z, x, y);
+    swizzle!(yzxz, Vec4, y, z, x, z);
+    swizzle!(yzyx, Vec4, y, z, y, x);
+    swizzle!(yzyy, Vec4, y, z, y, y);
+    swizzle!(yzyz, Vec4, y, z, y, z);
+    swizzle!(yzzx, Vec4, y, z, z, x);
+    swizzle!(yzzy, Vec4, y, z, z, y);
+    swizzle!(yzzz, Vec4, y, z, z, z);
+    swizzle!(zxxx, Vec4, z, x, x, x);
+    swizzle!(zxxy, Vec4, z, x, x, y);
+    swizzle!(zxxz, Vec4, z, x, x, z);
+    swizzle!(zxyx, Vec4, z, x, y, x);
+    swizzle!(zxyy, Vec4, z, x, y, y);
+    swizzle!(zxyz, Vec4, z, x, y, z);
+    swizzle!(zxzx, Vec4, z, x, z, x);
+    swizzle!(zxzy, Vec4, z, x, z, y);
+    swizzle!(zxzz, Vec4, z, x, z, z);
+    swizzle!(zyxx, Vec4, z, y, x, x);
+    swizzle!(zyxy, Vec4, z, y, x, y);
+    swizzle!(zyxz, Vec4, z, y, x, z);
+    swizzle!(zyyx, Vec4, z, y, y, x);
+    swizzle!(zyyy, Vec4, z, y, y, y);
+    swizzle!(zyyz, Vec4, z, y, y, z);
+    swizzle!(zyzx, Vec4, z, y, z, x);
+    swizzle!(zyzy, Vec4, z, y, z, y);
+    swizzle!(zyzz, Vec4, z, y, z, z);
+    swizzle!(zzxx, Vec4, z, z, x, x);
+    swizzle!(zzxy, Vec4, z, z, x, y);
+    swizzle!(zzxz, Vec4, z, z, x, z);
+    swizzle!(zzyx, Vec4, z, z, y, x);
+    swizzle!(zzyy, Vec4, z, z, y, y);
+    swizzle!(zzyz, Vec4, z, z, y, z);
+    swizzle!(zzzx, Vec4, z, z, z, x);
+    swizzle!(zzzy, Vec4, z, z, z, y);
+    swizzle!(zzzz, Vec4, z, z, z, z);
+}
+
 impl Add for Vec3 {
     type Output = Vec3;
 
@@ -294,6 +463,66 @@ impl Mul<Vec3> for f64 {
     }
 }
 
+impl Index<usize> for Vec3 {
+    type Output = f64;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("index out of bounds: Vec3 has 3 components, got index {index}"),
+        }
+    }
+}
+
+impl IndexMut<usize> for Vec3 {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => panic!("index out of bounds: Vec3 has 3 components, got index {index}"),
+        }
+    }
+}
+
+impl From<[f64; 3]> for Vec3 {
+    fn from(value: [f64; 3]) -> Self {
+        Self::new(value[0], value[1], value[2])
+    }
+}
+
+impl From<Vec3> for [f64; 3] {
+    fn from(value: Vec3) -> Self {
+        [value.x, value.y, value.z]
+    }
+}
+
+impl From<(f64, f64, f64)> for Vec3 {
+    fn from(value: (f64, f64, f64)) -> Self {
+        Self::new(value.0, value.1, value.2)
+    }
+}
+
+impl From<Vec3> for (f64, f64, f64) {
+    fn from(value: Vec3) -> Self {
+        (value.x, value.y, value.z)
+    }
+}
+
+impl From<Vec3> for VecN<3> {
+    fn from(value: Vec3) -> Self {
+        VecN::new([value.x, value.y, value.z])
+    }
+}
+
+impl From<VecN<3>> for Vec3 {
+    fn from(value: VecN<3>) -> Self {
+        Self::new(value.0[0], value.0[1], value.0[2])
+    }
+}
+
 pub type Point3 = Vec3;
 
 pub type ColorRGB = Vec3;
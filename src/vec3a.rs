@@ -0,0 +1,224 @@
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+#[cfg(feature = "simd")]
+use wide::f64x4;
+
+use crate::vec3::Vec3;
+
+/// A `Vec3` with the same value semantics, but aligned to a 4-lane f64
+/// register so hot-path math (dot products, normalization, ...) can run
+/// vectorized when the `simd` feature is enabled. Prefer plain `Vec3` for
+/// compact storage (e.g. in large buffers) and reach for `Vec3A` in tight
+/// loops; convert between them with `From`/`Into`.
+#[derive(Debug, Clone, Copy)]
+#[repr(align(32))]
+pub struct Vec3A {
+    #[cfg(feature = "simd")]
+    inner: f64x4,
+    #[cfg(not(feature = "simd"))]
+    x: f64,
+    #[cfg(not(feature = "simd"))]
+    y: f64,
+    #[cfg(not(feature = "simd"))]
+    z: f64,
+}
+
+impl Vec3A {
+    #[cfg(feature = "simd")]
+    pub fn new(x: f64, y: f64, z: f64) -> Self {
+        Self {
+            inner: f64x4::new([x, y, z, 0.0]),
+        }
+    }
+
+    #[cfg(not(feature = "simd"))]
+    pub fn new(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn splat(val: f64) -> Self {
+        Self::new(val, val, val)
+    }
+
+    #[cfg(feature = "simd")]
+    pub fn x(&self) -> f64 {
+        self.inner.to_array()[0]
+    }
+
+    #[cfg(not(feature = "simd"))]
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    #[cfg(feature = "simd")]
+    pub fn y(&self) -> f64 {
+        self.inner.to_array()[1]
+    }
+
+    #[cfg(not(feature = "simd"))]
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    #[cfg(feature = "simd")]
+    pub fn z(&self) -> f64 {
+        self.inner.to_array()[2]
+    }
+
+    #[cfg(not(feature = "simd"))]
+    pub fn z(&self) -> f64 {
+        self.z
+    }
+
+    #[cfg(feature = "simd")]
+    pub fn dot(&self, rhs: &Self) -> f64 {
+        (self.inner * rhs.inner).reduce_add()
+    }
+
+    #[cfg(not(feature = "simd"))]
+    pub fn dot(&self, rhs: &Self) -> f64 {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+    }
+
+    pub fn length_squared(&self) -> f64 {
+        self.dot(self)
+    }
+
+    pub fn length(&self) -> f64 {
+        self.length_squared().sqrt()
+    }
+
+    pub fn normalized(&self) -> Self {
+        *self / self.length()
+    }
+
+    pub fn cross(&self, rhs: &Self) -> Self {
+        Self::new(
+            self.y() * rhs.z() - self.z() * rhs.y(),
+            self.z() * rhs.x() - self.x() * rhs.z(),
+            self.x() * rhs.y() - self.y() * rhs.x(),
+        )
+    }
+}
+
+impl From<Vec3> for Vec3A {
+    fn from(value: Vec3) -> Self {
+        Self::new(value.x, value.y, value.z)
+    }
+}
+
+impl From<Vec3A> for Vec3 {
+    fn from(value: Vec3A) -> Self {
+        Vec3::new(value.x(), value.y(), value.z())
+    }
+}
+
+impl Add for Vec3A {
+    type Output = Self;
+
+    #[cfg(feature = "simd")]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            inner: self.inner + rhs.inner,
+        }
+    }
+
+    #[cfg(not(feature = "simd"))]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x() + rhs.x(), self.y() + rhs.y(), self.z() + rhs.z())
+    }
+}
+
+impl AddAssign for Vec3A {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Vec3A {
+    type Output = Self;
+
+    #[cfg(feature = "simd")]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            inner: self.inner - rhs.inner,
+        }
+    }
+
+    #[cfg(not(feature = "simd"))]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x() - rhs.x(), self.y() - rhs.y(), self.z() - rhs.z())
+    }
+}
+
+impl SubAssign for Vec3A {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Vec3A {
+    type Output = Self;
+
+    #[cfg(feature = "simd")]
+    fn neg(self) -> Self::Output {
+        Self { inner: -self.inner }
+    }
+
+    #[cfg(not(feature = "simd"))]
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x(), -self.y(), -self.z())
+    }
+}
+
+impl Mul<f64> for Vec3A {
+    type Output = Self;
+
+    #[cfg(feature = "simd")]
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self {
+            inner: self.inner * f64x4::splat(rhs),
+        }
+    }
+
+    #[cfg(not(feature = "simd"))]
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self::new(self.x() * rhs, self.y() * rhs, self.z() * rhs)
+    }
+}
+
+impl MulAssign<f64> for Vec3A {
+    fn mul_assign(&mut self, rhs: f64) {
+        *self = *self * rhs;
+    }
+}
+
+impl Mul<Vec3A> for f64 {
+    type Output = Vec3A;
+
+    fn mul(self, rhs: Vec3A) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl Div<f64> for Vec3A {
+    type Output = Self;
+
+    #[cfg(feature = "simd")]
+    fn div(self, rhs: f64) -> Self::Output {
+        Self {
+            inner: self.inner / f64x4::splat(rhs),
+        }
+    }
+
+    #[cfg(not(feature = "simd"))]
+    fn div(self, rhs: f64) -> Self::Output {
+        Self::new(self.x() / rhs, self.y() / rhs, self.z() / rhs)
+    }
+}
+
+impl DivAssign<f64> for Vec3A {
+    fn div_assign(&mut self, rhs: f64) {
+        *self = *self / rhs;
+    }
+}
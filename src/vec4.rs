@@ -1,10 +1,13 @@
 use std::ops::{
-    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, RangeInclusive, Sub, SubAssign,
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, RangeInclusive, Sub,
+    SubAssign,
 };
 
 use rand::{thread_rng, Rng};
+#[cfg(feature = "simd")]
+use wide::f64x4;
 
-use crate::{utils::linear_to_gamma, Vec3};
+use crate::{swizzle::swizzle, utils::linear_to_gamma, vec_n::VecN, Vec2, Vec3};
 
 #[derive(Debug, Default, Clone, Copy)]
 pub struct Vec4 {
@@ -47,6 +50,14 @@ impl Vec4 {
         self.length_squared().sqrt()
     }
 
+    #[cfg(feature = "simd")]
+    pub fn length_squared(&self) -> f64 {
+        let v = f64x4::new([self.x, self.y, self.z, self.w]);
+
+        (v * v).reduce_add()
+    }
+
+    #[cfg(not(feature = "simd"))]
     pub fn length_squared(&self) -> f64 {
         self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w
     }
@@ -55,12 +66,17 @@ impl Vec4 {
         *self / self.length()
     }
 
+    #[cfg(feature = "simd")]
     pub fn dot(&self, rhs: &Self) -> f64 {
-        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z + self.w * rhs.w
+        let a = f64x4::new([self.x, self.y, self.z, self.w]);
+        let b = f64x4::new([rhs.x, rhs.y, rhs.z, rhs.w]);
+
+        (a * b).reduce_add()
     }
 
-    pub fn truncate(&self) -> Vec3 {
-        Vec3::new(self.x, self.y, self.z)
+    #[cfg(not(feature = "simd"))]
+    pub fn dot(&self, rhs: &Self) -> f64 {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z + self.w * rhs.w
     }
 
     pub fn to_gamma(self) -> ColorRGBA {
@@ -78,6 +94,66 @@ impl Vec4 {
         (self.x.abs() < s) && (self.y.abs() < s) && (self.z.abs() < s) && (self.w.abs() < s)
     }
 
+    pub fn min_element(&self) -> f64 {
+        self.x.min(self.y).min(self.z).min(self.w)
+    }
+
+    pub fn max_element(&self) -> f64 {
+        self.x.max(self.y).max(self.z).max(self.w)
+    }
+
+    pub fn abs(&self) -> Self {
+        Self::new(self.x.abs(), self.y.abs(), self.z.abs(), self.w.abs())
+    }
+
+    pub fn floor(&self) -> Self {
+        Self::new(
+            self.x.floor(),
+            self.y.floor(),
+            self.z.floor(),
+            self.w.floor(),
+        )
+    }
+
+    pub fn ceil(&self) -> Self {
+        Self::new(self.x.ceil(), self.y.ceil(), self.z.ceil(), self.w.ceil())
+    }
+
+    pub fn clamp(&self, min: Self, max: Self) -> Self {
+        Self::new(
+            self.x.clamp(min.x, max.x),
+            self.y.clamp(min.y, max.y),
+            self.z.clamp(min.z, max.z),
+            self.w.clamp(min.w, max.w),
+        )
+    }
+
+    pub fn component_min(&self, rhs: Self) -> Self {
+        Self::new(
+            self.x.min(rhs.x),
+            self.y.min(rhs.y),
+            self.z.min(rhs.z),
+            self.w.min(rhs.w),
+        )
+    }
+
+    pub fn component_max(&self, rhs: Self) -> Self {
+        Self::new(
+            self.x.max(rhs.x),
+            self.y.max(rhs.y),
+            self.z.max(rhs.z),
+            self.w.max(rhs.w),
+        )
+    }
+
+    pub fn iter(&self) -> std::array::IntoIter<f64, 4> {
+        [self.x, self.y, self.z, self.w].into_iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::array::IntoIter<&mut f64, 4> {
+        [&mut self.x, &mut self.y, &mut self.z, &mut self.w].into_iter()
+    }
+
     pub fn random() -> Self {
         let mut rng = thread_rng();
         Self::new(
@@ -103,6 +179,365 @@ impl Vec4 {
     }
 }
 
+/// GLSL-style swizzle accessors (`v.xy()`, `color.wxyz()`, ...), generated
+/// by the `swizzle!` macro for every two/three/four-component permutation.
+/// `xyz()` is the canonical way to drop the `w` component, replacing the
+/// old ad-hoc `truncate`.
+impl Vec4 {
+    swizzle!(xx, Vec2, x, x);
+    swizzle!(xy, Vec2, x, y);
+    swizzle!(xz, Vec2, x, z);
+    swizzle!(xw, Vec2, x, w);
+    swizzle!(yx, Vec2, y, x);
+    swizzle!(yy, Vec2, y, y);
+    swizzle!(yz, Vec2, y, z);
+    swizzle!(yw, Vec2, y, w);
+    swizzle!(zx, Vec2, z, x);
+    swizzle!(zy, Vec2, z, y);
+    swizzle!(zz, Vec2, z, z);
+    swizzle!(zw, Vec2, z, w);
+    swizzle!(wx, Vec2, w, x);
+    swizzle!(wy, Vec2, w, y);
+    swizzle!(wz, Vec2, w, z);
+    swizzle!(ww, Vec2, w, w);
+
+    swizzle!(xxx, Vec3, x, x, x);
+    swizzle!(xxy, Vec3, x, x, y);
+    swizzle!(xxz, Vec3, x, x, z);
+    swizzle!(xxw, Vec3, x, x, w);
+    swizzle!(xyx, Vec3, x, y, x);
+    swizzle!(xyy, Vec3, x, y, y);
+    swizzle!(xyz, Vec3, x, y, z);
+    swizzle!(xyw, Vec3, x, y, w);
+    swizzle!(xzx, Vec3, x, z, x);
+    swizzle!(xzy, Vec3, x, z, y);
+    swizzle!(xzz, Vec3, x, z, z);
+    swizzle!(xzw, Vec3, x, z, w);
+    swizzle!(xwx, Vec3, x, w, x);
+    swizzle!(xwy, Vec3, x, w, y);
+    swizzle!(xwz, Vec3, x, w, z);
+    swizzle!(xww, Vec3, x, w, w);
+    swizzle!(yxx, Vec3, y, x, x);
+    swizzle!(yxy, Vec3, y, x, y);
+    swizzle!(yxz, Vec3, y, x, z);
+    swizzle!(yxw, Vec3, y, x, w);
+    swizzle!(yyx, Vec3, y, y, x);
+    swizzle!(yyy, Vec3, y, y, y);
+    swizzle!(yyz, Vec3, y, y, z);
+    swizzle!(yyw, Vec3, y, y, w);
+    swizzle!(yzx, Vec3, y, z, x);
+    swizzle!(yzy, Vec3, y, z, y);
+    swizzle!(yzz, Vec3, y, z, z);
+    swizzle!(yzw, Vec3, y, z, w);
+    swizzle!(ywx, Vec3, y, w, x);
+    swizzle!(ywy, Vec3, y, w, y);
+    swizzle!(ywz, Vec3, y, w, z);
+    swizzle!(yww, Vec3, y, w, w);
+    swizzle!(zxx, Vec3, z, x, x);
+    swizzle!(zxy, Vec3, z, x, y);
+    swizzle!(zxz, Vec3, z, x, z);
+    swizzle!(zxw, Vec3, z, x, w);
+    swizzle!(zyx, Vec3, z, y, x);
+    swizzle!(zyy, Vec3, z, y, y);
+    swizzle!(zyz, Vec3, z, y, z);
+    swizzle!(zyw, Vec3, z, y, w);
+    swizzle!(zzx, Vec3, z, z, x);
+    swizzle!(zzy, Vec3, z, z, y);
+    swizzle!(zzz, Vec3, z, z, z);
+    swizzle!(zzw, Vec3, z, z, w);
+    swizzle!(zwx, Vec3, z, w, x);
+    swizzle!(zwy, Vec3, z, w, y);
+    swizzle!(zwz, Vec3, z, w, z);
+    swizzle!(zww, Vec3, z, w, w);
+    swizzle!(wxx, Vec3, w, x, x);
+    swizzle!(wxy, Vec3, w, x, y);
+    swizzle!(wxz, Vec3, w, x, z);
+    swizzle!(wxw, Vec3, w, x, w);
+    swizzle!(wyx, Vec3, w, y, x);
+    swizzle!(wyy, Vec3, w, y, y);
+    swizzle!(wyz, Vec3, w, y, z);
+    swizzle!(wyw, Vec3, w, y, w);
+    swizzle!(wzx, Vec3, w, z, x);
+    swizzle!(wzy, Vec3, w, z, y);
+    swizzle!(wzz, Vec3, w, z, z);
+    swizzle!(wzw, Vec3, w, z, w);
+    swizzle!(wwx, Vec3, w, w, x);
+    swizzle!(wwy, Vec3, w, w, y);
+    swizzle!(wwz, Vec3, w, w, z);
+    swizzle!(www, Vec3, w, w, w);
+
+    swizzle!(xxxx, Vec4, x, x, x, x);
+    swizzle!(xxxy, Vec4, x, x, x, y);
+    swizzle!(xxxz, Vec4, x, x, x, z);
+    swizzle!(xxxw, Vec4, x, x, x, w);
+    swizzle!(xxyx, Vec4, x, x, y, x);
+    swizzle!(xxyy, Vec4, x, x, y, y);
+    swizzle!(xxyz, Vec4, x, x, y, z);
+    swizzle!(xxyw, Vec4, x, x, y, w);
+    swizzle!(xxzx, Vec4, x, x, z, x);
+    swizzle!(xxzy, Vec4, x, x, z, y);
+    swizzle!(xxzz, Vec4, x, x, z, z);
+    swizzle!(xxzw, Vec4, x, x, z, w);
+    swizzle!(xxwx, Vec4, x, x, w, x);
+    swizzle!(xxwy, Vec4, x, x, w, y);
+    swizzle!(xxwz, Vec4, x, x, w, z);
+    swizzle!(xxww, Vec4, x, x, w, w);
+    swizzle!(xyxx, Vec4, x, y, x, x);
+    swizzle!(xyxy, Vec4, x, y, x, y);
+    swizzle!(xyxz, Vec4, x, y, x, z);
+    swizzle!(xyxw, Vec4, x, y, x, w);
+    swizzle!(xyyx, Vec4, x, y, y, x);
+    swizzle!(xyyy, Vec4, x, y, y, y);
+    swizzle!(xyyz, Vec4, x, y, y, z);
+    swizzle!(xyyw, Vec4, x, y, y, w);
+    swizzle!(xyzx, Vec4, x, y, z, x);
+    swizzle!(xyzy, Vec4, x, y, z, y);
+    swizzle!(xyzz, Vec4, x, y, z, z);
+    swizzle!(xyzw, Vec4, x, y, z, w);
+    swizzle!(xywx, Vec4, x, y, w, x);
+    swizzle!(xywy, Vec4, x, y, w, y);
+    swizzle!(xywz, Vec4, x, y, w, z);
+    swizzle!(xyww, Vec4, x, y, w, w);
+    swizzle!(xzxx, Vec4, x, z, x, x);
+    swizzle!(xzxy, Vec4, x, z, x, y);
+    swizzle!(xzxz, Vec4, x, z, x, z);
+    swizzle!(xzxw, Vec4, x, z, x, w);
+    swizzle!(xzyx, Vec4, x, z, y, x);
+    swizzle!(xzyy, Vec4, x, z, y, y);
+    swizzle!(xzyz, Vec4, x, z, y, z);
+    swizzle!(xzyw, Vec4, x, z, y, w);
+    swizzle!(xzzx, Vec4, x, z, z, x);
+    swizzle!(xzzy, Vec4, x, z, z, y);
+    swizzle!(xzzz, Vec4, x, z, z, z);
+    swizzle!(xzzw, Vec4, x, z, z, w);
+    swizzle!(xzwx, Vec4, x, z, w, x);
+    swizzle!(xzwy, Vec4, x, z, w, y);
+    swizzle!(xzwz, Vec4, x, z, w, z);
+    swizzle!(xzww, Vec4, x, z, w, w);
+    swizzle!(xwxx, Vec4, x, w, x, x);
+    swizzle!(xwxy, Vec4, x, w, x, y);
+    swizzle!(xwxz, Vec4, x, w, x, z);
+    swizzle!(xwxw, Vec4, x, w, x, w);
+    swizzle!(xwyx, Vec4, x, w, y, x);
+    swizzle!(xwyy, Vec4, x, w, y, y);
+    swizzle!(xwyz, Vec4, x, w, y, z);
+    swizzle!(xwyw, Vec4, x, w, y, w);
+    swizzle!(xwzx, Vec4, x, w, z, x);
+    swizzle!(xwzy, Vec4, x, w, z, y);
+    swizzle!(xwzz, Vec4, x, w, z, z);
+    swizzle!(xwzw, Vec4, x, w, z, w);
+    swizzle!(xwwx, Vec4, x, w, w, x);
+    swizzle!(xwwy, Vec4, x, w, w, y);
+    swizzle!(xwwz, Vec4, x, w, w, z);
+    swizzle!(xwww, Vec4, x, w, w, w);
+    swizzle!(yxxx, Vec4, y, x, x, x);
+    swizzle!(yxxy, Vec4, y, x, x, y);
+    swizzle!(yxxz, Vec4, y, x, x, z);
+    swizzle!(yxxw, Vec4, y, x, x, w);
+    swizzle!(yxyx, Vec4, y, x, y, x);
+    swizzle!(yxyy, Vec4, y, x, y, y);
+    swizzle!(yxyz, Vec4, y, x, y, z);
+    swizzle!(yxyw, Vec4, y, x, y, w);
+    swizzle!(yxzx, Vec4, y, x, z, x);
+    swizzle!(yxzy, Vec4, y, x, z, y);
+    swizzle!(yxzz, Vec4, y, x, z, z);
+    swizzle!(yxzw, Vec4, y, x, z, w);
+    swizzle!(yxwx, Vec4, y, x, w, x);
+    swizzle!(yxwy, Vec4, y, x, w, y);
+    swizzle!(yxwz, Vec4, y, x, w, z);
+    swizzle!(yxww, Vec4, y, x, w, w);
+    swizzle!(yyxx, Vec4, y, y, x, x);
+    swizzle!(yyxy, Vec4, y, y, x, y);
+    swizzle!(yyxz, Vec4, y, y, x, z);
+    swizzle!(yyxw, Vec4, y, y, x, w);
+    swizzle!(yyyx, Vec4, y, y, y, x);
+    swizzle!(yyyy, Vec4, y, y, y, y);
+    swizzle!(yyyz, Vec4, y, y, y, z);
+    swizzle!(yyyw, Vec4, y, y, y, w);
+    swizzle!(yyzx, Vec4, y, y, z, x);
+    swizzle!(yyzy, Vec4, y, y, z, y);
+    swizzle!(yyzz, Vec4, y, y, z, z);
+    swizzle!(yyzw, Vec4, y, y, z, w);
+    swizzle!(yywx, Vec4, y, y, w, x);
+    swizzle!(yywy, Vec4, y, y, w, y);
+    swizzle!(yywz, Vec4, y, y, w, z);
+    swizzle!(yyww, Vec4, y, y, w, w);
+    swizzle!(yzxx, Vec4, y, z, x, x);
+    swizzle!(yzxy, Vec4, y, z, x, y);
+    swizzle!(yzxz, Vec4, y, z, x, z);
+    swizzle!(yzxw, Vec4, y, z, x, w);
+    swizzle!(yzyx, Vec4, y, z, y, x);
+    swizzle!(yzyy, Vec4, y, z, y, y);
+    swizzle!(yzyz, Vec4, y, z, y, z);
+    swizzle!(yzyw, Vec4, y, z, y, w);
+    swizzle!(yzzx, Vec4, y, z, z, x);
+    swizzle!(yzzy, Vec4, y, z, z, y);
+    swizzle!(yzzz, Vec4, y, z, z, z);
+    swizzle!(yzzw, Vec4, y, z, z, w);
+    swizzle!(yzwx, Vec4, y, z, w, x);
+    swizzle!(yzwy, Vec4, y, z, w, y);
+    swizzle!(yzwz, Vec4, y, z, w, z);
+    swizzle!(yzww, Vec4, y, z, w, w);
+    swizzle!(ywxx, Vec4, y, w, x, x);
+    swizzle!(ywxy, Vec4, y, w, x, y);
+    swizzle!(ywxz, Vec4, y, w, x, z);
+    swizzle!(ywxw, Vec4, y, w, x, w);
+    swizzle!(ywyx, Vec4, y, w, y, x);
+    swizzle!(ywyy, Vec4, y, w, y, y);
+    swizzle!(ywyz, Vec4, y, w, y, z);
+    swizzle!(ywyw, Vec4, y, w, y, w);
+    swizzle!(ywzx, Vec4, y, w, z, x);
+    swizzle!(ywzy, Vec4, y, w, z, y);
+    swizzle!(ywzz, Vec4, y, w, z, z);
+    swizzle!(ywzw, Vec4, y, w, z, w);
+    swizzle!(ywwx, Vec4, y, w, w, x);
+    swizzle!(ywwy, Vec4, y, w, w, y);
+    swizzle!(ywwz, Vec4, y, w, w, z);
+    swizzle!(ywww, Vec4, y, w, w, w);
+    swizzle!(zxxx, Vec4, z, x, x, x);
+    swizzle!(zxxy, Vec4, z, x, x, y);
+    swizzle!(zxxz, Vec4, z, x, x, z);
+    swizzle!(zxxw, Vec4, z, x, x, w);
+    swizzle!(zxyx, Vec4, z, x, y, x);
+    swizzle!(zxyy, Vec4, z, x, y, y);
+    swizzle!(zxyz, Vec4, z, x, y, z);
+    swizzle!(zxyw, Vec4, z, x, y, w);
+    swizzle!(zxzx, Vec4, z, x, z, x);
+    swizzle!(zxzy, Vec4, z, x, z, y);
+    swizzle!(zxzz, Vec4, z, x, z, z);
+    swizzle!(zxzw, Vec4, z, x, z, w);
+    swizzle!(zxwx, Vec4, z, x, w, x);
+    swizzle!(zxwy, Vec4, z, x, w, y);
+    swizzle!(zxwz, Vec4, z, x, w, z);
+    swizzle!(zxww, Vec4, z, x, w, w);
+    swizzle!(zyxx, Vec4, z, y, x, x);
+    swizzle!(zyxy, Vec4, z, y, x, y);
+    swizzle!(zyxz, Vec4, z, y, x, z);
+    swizzle!(zyxw, Vec4, z, y, x, w);
+    swizzle!(zyyx, Vec4, z, y, y, x);
+    swizzle!(zyyy, Vec4, z, y, y, y);
+    swizzle!(zyyz, Vec4, z, y, y, z);
+    swizzle!(zyyw, Vec4, z, y, y, w);
+    swizzle!(zyzx, Vec4, z, y, z, x);
+    swizzle!(zyzy, Vec4, z, y, z, y);
+    swizzle!(zyzz, Vec4, z, y, z, z);
+    swizzle!(zyzw, Vec4, z, y, z, w);
+    swizzle!(zywx, Vec4, z, y, w, x);
+    swizzle!(zywy, Vec4, z, y, w, y);
+    swizzle!(zywz, Vec4, z, y, w, z);
+    swizzle!(zyww, Vec4, z, y, w, w);
+    swizzle!(zzxx, Vec4, z, z, x, x);
+    swizzle!(zzxy, Vec4, z, z, x, y);
+    swizzle!(zzxz, Vec4, z, z, x, z);
+    swizzle!(zzxw, Vec4, z, z, x, w);
+    swizzle!(zzyx, Vec4, z, z, y, x);
+    swizzle!(zzyy, Vec4, z, z, y, y);
+    swizzle!(zzyz, Vec4, z, z, y, z);
+    swizzle!(zzyw, Vec4, z, z, y, w);
+    swizzle!(zzzx, Vec4, z, z, z, x);
+    swizzle!(zzzy, Vec4, z, z, z, y);
+    swizzle!(zzzz, Vec4, z, z, z, z);
+    swizzle!(zzzw, Vec4, z, z, z, w);
+    swizzle!(zzwx, Vec4, z, z, w, x);
+    swizzle!(zzwy, Vec4, z, z, w, y);
+    swizzle!(zzwz, Vec4, z, z, w, z);
+    swizzle!(zzww, Vec4, z, z, w, w);
+    swizzle!(zwxx, Vec4, z, w, x, x);
+    swizzle!(zwxy, Vec4, z, w, x, y);
+    swizzle!(zwxz, Vec4, z, w, x, z);
+    swizzle!(zwxw, Vec4, z, w, x, w);
+    swizzle!(zwyx, Vec4, z, w, y, x);
+    swizzle!(zwyy, Vec4, z, w, y, y);
+    swizzle!(zwyz, Vec4, z, w, y, z);
+    swizzle!(zwyw, Vec4, z, w, y, w);
+    swizzle!(zwzx, Vec4, z, w, z, x);
+    swizzle!(zwzy, Vec4, z, w, z, y);
+    swizzle!(zwzz, Vec4, z, w, z, z);
+    swizzle!(zwzw, Vec4, z, w, z, w);
+    swizzle!(zwwx, Vec4, z, w, w, x);
+    swizzle!(zwwy, Vec4, z, w, w, y);
+    swizzle!(zwwz, Vec4, z, w, w, z);
+    swizzle!(zwww, Vec4, z, w, w, w);
+    swizzle!(wxxx, Vec4, w, x, x, x);
+    swizzle!(wxxy, Vec4, w, x, x, y);
+    swizzle!(wxxz, Vec4, w, x, x, z);
+    swizzle!(wxxw, Vec4, w, x, x, w);
+    swizzle!(wxyx, Vec4, w, x, y, x);
+    swizzle!(wxyy, Vec4, w, x, y, y);
+    swizzle!(wxyz, Vec4, w, x, y, z);
+    swizzle!(wxyw, Vec4, w, x, y, w);
+    swizzle!(wxzx, Vec4, w, x, z, x);
+    swizzle!(wxzy, Vec4, w, x, z, y);
+    swizzle!(wxzz, Vec4, w, x, z, z);
+    swizzle!(wxzw, Vec4, w, x, z, w);
+    swizzle!(wxwx, Vec4, w, x, w, x);
+    swizzle!(wxwy, Vec4, w, x, w, y);
+    swizzle!(wxwz, Vec4, w, x, w, z);
+    swizzle!(wxww, Vec4, w, x, w, w);
+    swizzle!(wyxx, Vec4, w, y, x, x);
+    swizzle!(wyxy, Vec4, w, y, x, y);
+    swizzle!(wyxz, Vec4, w, y, x, z);
+    swizzle!(wyxw, Vec4, w, y, x, w);
+    swizzle!(wyyx, Vec4, w, y, y, x);
+    swizzle!(wyyy, Vec4, w, y, y, y);
+    swizzle!(wyyz, Vec4, w, y, y, z);
+    swizzle!(wyyw, Vec4, w, y, y, w);
+    swizzle!(wyzx, Vec4, w, y, z, x);
+    swizzle!(wyzy, Vec4, w, y, z, y);
+    swizzle!(wyzz, Vec4, w, y, z, z);
+    swizzle!(wyzw, Vec4, w, y, z, w);
+    swizzle!(wywx, Vec4, w, y, w, x);
+    swizzle!(wywy, Vec4, w, y, w, y);
+    swizzle!(wywz, Vec4, w, y, w, z);
+    swizzle!(wyww, Vec4, w, y, w, w);
+    swizzle!(wzxx, Vec4, w, z, x, x);
+    swizzle!(wzxy, Vec4, w, z, x, y);
+    swizzle!(wzxz, Vec4, w, z, x, z);
+    swizzle!(wzxw, Vec4, w, z, x, w);
+    swizzle!(wzyx, Vec4, w, z, y, x);
+    swizzle!(wzyy, Vec4, w, z, y, y);
+    swizzle!(wzyz, Vec4, w, z, y, z);
+    swizzle!(wzyw, Vec4, w, z, y, w);
+    swizzle!(wzzx, Vec4, w, z, z, x);
+    swizzle!(wzzy, Vec4, w, z, z, y);
+    swizzle!(wzzz, Vec4, w, z, z, z);
+    swizzle!(wzzw, Vec4, w, z, z, w);
+    swizzle!(wzwx, Vec4, w, z, w, x);
+    swizzle!(wzwy, Vec4, w, z, w, y);
+    swizzle!(wzwz, Vec4, w, z, w, z);
+    swizzle!(wzww, Vec4, w, z, w, w);
+    swizzle!(wwxx, Vec4, w, w, x, x);
+    swizzle!(wwxy, Vec4, w, w, x, y);
+    swizzle!(wwxz, Vec4, w, w, x, z);
+    swizzle!(wwxw, Vec4, w, w, x, w);
+    swizzle!(wwyx, Vec4, w, w, y, x);
+    swizzle!(wwyy, Vec4, w, w, y, y);
+    swizzle!(wwyz, Vec4, w, w, y, z);
+    swizzle!(wwyw, Vec4, w, w, y, w);
+    swizzle!(wwzx, Vec4, w, w, z, x);
+    swizzle!(wwzy, Vec4, w, w, z, y);
+    swizzle!(wwzz, Vec4, w, w, z, z);
+    swizzle!(wwzw, Vec4, w, w, z, w);
+    swizzle!(wwwx, Vec4, w, w, w, x);
+    swizzle!(wwwy, Vec4, w, w, w, y);
+    swizzle!(wwwz, Vec4, w, w, w, z);
+    swizzle!(wwww, Vec4, w, w, w, w);
+}
+
+#[cfg(feature = "simd")]
+impl Add for Vec4 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let r = (f64x4::new([self.x, self.y, self.z, self.w])
+            + f64x4::new([rhs.x, rhs.y, rhs.z, rhs.w]))
+        .to_array();
+
+        Self::new(r[0], r[1], r[2], r[3])
+    }
+}
+
+#[cfg(not(feature = "simd"))]
 impl Add for Vec4 {
     type Output = Self;
 
@@ -122,6 +557,20 @@ impl AddAssign for Vec4 {
     }
 }
 
+#[cfg(feature = "simd")]
+impl Sub for Vec4 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let r = (f64x4::new([self.x, self.y, self.z, self.w])
+            - f64x4::new([rhs.x, rhs.y, rhs.z, rhs.w]))
+        .to_array();
+
+        Self::new(r[0], r[1], r[2], r[3])
+    }
+}
+
+#[cfg(not(feature = "simd"))]
 impl Sub for Vec4 {
     type Output = Self;
 
@@ -149,6 +598,20 @@ impl Neg for Vec4 {
     }
 }
 
+#[cfg(feature = "simd")]
+impl Mul<Vec4> for Vec4 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let r = (f64x4::new([self.x, self.y, self.z, self.w])
+            * f64x4::new([rhs.x, rhs.y, rhs.z, rhs.w]))
+        .to_array();
+
+        Self::new(r[0], r[1], r[2], r[3])
+    }
+}
+
+#[cfg(not(feature = "simd"))]
 impl Mul<Vec4> for Vec4 {
     type Output = Self;
 
@@ -187,6 +650,20 @@ impl MulAssign<f64> for Vec4 {
     }
 }
 
+#[cfg(feature = "simd")]
+impl Div<Vec4> for Vec4 {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        let r = (f64x4::new([self.x, self.y, self.z, self.w])
+            / f64x4::new([rhs.x, rhs.y, rhs.z, rhs.w]))
+        .to_array();
+
+        Self::new(r[0], r[1], r[2], r[3])
+    }
+}
+
+#[cfg(not(feature = "simd"))]
 impl Div<Vec4> for Vec4 {
     type Output = Self;
 
@@ -233,6 +710,68 @@ impl Mul<Vec4> for f64 {
     }
 }
 
+impl Index<usize> for Vec4 {
+    type Output = f64;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            3 => &self.w,
+            _ => panic!("index out of bounds: Vec4 has 4 components, got index {index}"),
+        }
+    }
+}
+
+impl IndexMut<usize> for Vec4 {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            3 => &mut self.w,
+            _ => panic!("index out of bounds: Vec4 has 4 components, got index {index}"),
+        }
+    }
+}
+
+impl From<[f64; 4]> for Vec4 {
+    fn from(value: [f64; 4]) -> Self {
+        Self::new(value[0], value[1], value[2], value[3])
+    }
+}
+
+impl From<Vec4> for [f64; 4] {
+    fn from(value: Vec4) -> Self {
+        [value.x, value.y, value.z, value.w]
+    }
+}
+
+impl From<(f64, f64, f64, f64)> for Vec4 {
+    fn from(value: (f64, f64, f64, f64)) -> Self {
+        Self::new(value.0, value.1, value.2, value.3)
+    }
+}
+
+impl From<Vec4> for (f64, f64, f64, f64) {
+    fn from(value: Vec4) -> Self {
+        (value.x, value.y, value.z, value.w)
+    }
+}
+
+impl From<Vec4> for VecN<4> {
+    fn from(value: Vec4) -> Self {
+        VecN::new([value.x, value.y, value.z, value.w])
+    }
+}
+
+impl From<VecN<4>> for Vec4 {
+    fn from(value: VecN<4>) -> Self {
+        Self::new(value.0[0], value.0[1], value.0[2], value.0[3])
+    }
+}
+
 #[allow(unused)]
 pub type Point4 = Vec4;
 
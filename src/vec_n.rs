@@ -0,0 +1,186 @@
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+
+/// Generic N-dimensional vector, for work beyond the hand-written
+/// Vec2/Vec3/Vec4 (color spectra, state vectors, small linear solves).
+/// Vec2/Vec3/Vec4 stay the ergonomic named-field types for graphics code;
+/// convert to/from `VecN` at those dimensions with `From`/`Into`.
+#[derive(Debug, Clone, Copy)]
+pub struct VecN<const N: usize>(pub [f64; N]);
+
+impl<const N: usize> VecN<N> {
+    pub const fn new(components: [f64; N]) -> Self {
+        Self(components)
+    }
+
+    pub const fn splat(val: f64) -> Self {
+        Self([val; N])
+    }
+
+    pub fn dot(&self, rhs: &Self) -> f64 {
+        self.0.iter().zip(rhs.0.iter()).map(|(a, b)| a * b).sum()
+    }
+
+    pub fn length_squared(&self) -> f64 {
+        self.dot(self)
+    }
+
+    pub fn length(&self) -> f64 {
+        self.length_squared().sqrt()
+    }
+
+    pub fn normalized(&self) -> Self {
+        *self / self.length()
+    }
+
+    pub fn component_min(&self, rhs: &Self) -> Self {
+        Self(zip_map(&self.0, &rhs.0, |a, b| a.min(b)))
+    }
+
+    pub fn component_max(&self, rhs: &Self) -> Self {
+        Self(zip_map(&self.0, &rhs.0, |a, b| a.max(b)))
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, f64> {
+        self.0.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, f64> {
+        self.0.iter_mut()
+    }
+}
+
+fn zip_map<const N: usize>(a: &[f64; N], b: &[f64; N], f: impl Fn(f64, f64) -> f64) -> [f64; N] {
+    let mut out = [0.0; N];
+
+    for (out_i, (a_i, b_i)) in out.iter_mut().zip(a.iter().zip(b.iter())) {
+        *out_i = f(*a_i, *b_i);
+    }
+
+    out
+}
+
+impl<const N: usize> Index<usize> for VecN<N> {
+    type Output = f64;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<const N: usize> IndexMut<usize> for VecN<N> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+impl<const N: usize> Add for VecN<N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(zip_map(&self.0, &rhs.0, |a, b| a + b))
+    }
+}
+
+impl<const N: usize> AddAssign for VecN<N> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const N: usize> Sub for VecN<N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(zip_map(&self.0, &rhs.0, |a, b| a - b))
+    }
+}
+
+impl<const N: usize> SubAssign for VecN<N> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const N: usize> Neg for VecN<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        let mut out = [0.0; N];
+
+        for (out_i, a_i) in out.iter_mut().zip(self.0.iter()) {
+            *out_i = -a_i;
+        }
+
+        Self(out)
+    }
+}
+
+impl<const N: usize> Mul<VecN<N>> for VecN<N> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self(zip_map(&self.0, &rhs.0, |a, b| a * b))
+    }
+}
+
+impl<const N: usize> MulAssign<VecN<N>> for VecN<N> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const N: usize> Mul<f64> for VecN<N> {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        let mut out = [0.0; N];
+
+        for (out_i, a_i) in out.iter_mut().zip(self.0.iter()) {
+            *out_i = a_i * rhs;
+        }
+
+        Self(out)
+    }
+}
+
+impl<const N: usize> MulAssign<f64> for VecN<N> {
+    fn mul_assign(&mut self, rhs: f64) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const N: usize> Div<VecN<N>> for VecN<N> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Self(zip_map(&self.0, &rhs.0, |a, b| a / b))
+    }
+}
+
+impl<const N: usize> DivAssign<VecN<N>> for VecN<N> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const N: usize> Div<f64> for VecN<N> {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        let mut out = [0.0; N];
+
+        for (out_i, a_i) in out.iter_mut().zip(self.0.iter()) {
+            *out_i = a_i / rhs;
+        }
+
+        Self(out)
+    }
+}
+
+impl<const N: usize> DivAssign<f64> for VecN<N> {
+    fn div_assign(&mut self, rhs: f64) {
+        *self = *self / rhs;
+    }
+}